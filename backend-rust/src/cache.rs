@@ -0,0 +1,5 @@
+use redis::{Client, RedisResult};
+
+pub fn init_client(redis_url: &str) -> RedisResult<Client> {
+    Client::open(redis_url)
+}
@@ -0,0 +1,31 @@
+use config::{Config, ConfigError, Environment, File};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Settings {
+    pub database_url: String,
+    pub redis_url: String,
+    pub server_host: String,
+    pub server_port: u16,
+    pub jwt_secret: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: String,
+    pub smtp_password: String,
+    pub smtp_from_address: String,
+    pub password_reset_url_base: String,
+    pub login_fail_threshold: u32,
+    pub login_fail_window_minutes: i64,
+    pub email_verification_url_base: String,
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, ConfigError> {
+        let config = Config::builder()
+            .add_source(File::with_name("config/default").required(false))
+            .add_source(Environment::default().separator("__"))
+            .build()?;
+
+        config.try_deserialize()
+    }
+}
@@ -0,0 +1,8 @@
+use sqlx::postgres::{PgPool, PgPoolOptions};
+
+pub async fn init_pool(database_url: &str) -> Result<PgPool, sqlx::Error> {
+    PgPoolOptions::new()
+        .max_connections(10)
+        .connect(database_url)
+        .await
+}
@@ -0,0 +1,175 @@
+use thiserror::Error;
+use tonic::{Code, Status};
+
+/// Domain errors raised by the service. Each variant carries its own `tonic::Code` and a
+/// stable, namespaced string code (see [`AppError::error_code`]) so that clients can branch on
+/// machine-readable codes instead of parsing human-readable messages.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("validation error: {0}")]
+    ValidationError(String),
+
+    #[error("invalid credentials")]
+    InvalidCredentials,
+
+    #[error("email not verified")]
+    EmailNotVerified,
+
+    #[error("user is blocked or no longer exists")]
+    UserBlocked,
+
+    #[error("too many failed login attempts")]
+    TooManyAttempts,
+
+    #[error("access token has been revoked")]
+    TokenRevoked,
+
+    #[error("invalid or expired refresh token")]
+    InvalidRefreshToken,
+
+    #[error("invalid or expired password reset token")]
+    InvalidResetToken,
+
+    #[error("invalid or expired email verification token")]
+    InvalidVerificationToken,
+
+    #[error("database error: {0}")]
+    DbError(#[from] sqlx::Error),
+
+    #[error("redis error: {0}")]
+    RedisError(#[from] redis::RedisError),
+
+    #[error("jwt error: {0}")]
+    JwtError(#[from] jsonwebtoken::errors::Error),
+
+    #[error("password hashing error: {0}")]
+    HashError(String),
+
+    #[error("email delivery error: {0}")]
+    EmailError(String),
+
+    #[error("configuration error: {0}")]
+    ConfigError(#[from] config::ConfigError),
+
+    #[error("transport error: {0}")]
+    TransportError(#[from] tonic::transport::Error),
+
+    #[error("invalid server address: {0}")]
+    AddrParseError(#[from] std::net::AddrParseError),
+
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+impl From<argon2::password_hash::Error> for AppError {
+    fn from(err: argon2::password_hash::Error) -> Self {
+        AppError::HashError(err.to_string())
+    }
+}
+
+impl AppError {
+    /// The gRPC status code a client should see for this error.
+    fn code(&self) -> Code {
+        match self {
+            AppError::ValidationError(_) => Code::InvalidArgument,
+            AppError::InvalidCredentials
+            | AppError::EmailNotVerified
+            | AppError::UserBlocked
+            | AppError::TokenRevoked
+            | AppError::InvalidRefreshToken
+            | AppError::JwtError(_) => Code::Unauthenticated,
+            AppError::InvalidResetToken | AppError::InvalidVerificationToken => Code::NotFound,
+            AppError::TooManyAttempts => Code::ResourceExhausted,
+            AppError::DbError(_)
+            | AppError::RedisError(_)
+            | AppError::HashError(_)
+            | AppError::EmailError(_)
+            | AppError::ConfigError(_)
+            | AppError::TransportError(_)
+            | AppError::AddrParseError(_)
+            | AppError::Internal(_) => Code::Internal,
+        }
+    }
+
+    /// A stable, namespaced error code frontends can branch on (e.g. `AUTH_INVALID_CREDENTIALS`).
+    fn error_code(&self) -> &'static str {
+        match self {
+            AppError::ValidationError(_) => "VALIDATION_FAILED",
+            AppError::InvalidCredentials => "AUTH_INVALID_CREDENTIALS",
+            AppError::EmailNotVerified => "AUTH_EMAIL_NOT_VERIFIED",
+            AppError::UserBlocked => "AUTH_USER_BLOCKED",
+            AppError::TooManyAttempts => "AUTH_TOO_MANY_ATTEMPTS",
+            AppError::JwtError(_) => "AUTH_INVALID_TOKEN",
+            AppError::TokenRevoked => "AUTH_TOKEN_REVOKED",
+            AppError::InvalidRefreshToken => "AUTH_INVALID_REFRESH_TOKEN",
+            AppError::InvalidResetToken => "AUTH_INVALID_RESET_TOKEN",
+            AppError::InvalidVerificationToken => "AUTH_INVALID_VERIFICATION_TOKEN",
+            AppError::DbError(_)
+            | AppError::RedisError(_)
+            | AppError::HashError(_)
+            | AppError::EmailError(_)
+            | AppError::ConfigError(_)
+            | AppError::TransportError(_)
+            | AppError::AddrParseError(_)
+            | AppError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// The message sent to the client. Internal failures get a generic message; the real cause
+    /// is only ever logged, never returned, to avoid leaking implementation details.
+    fn client_message(&self) -> String {
+        match self {
+            AppError::DbError(e) => {
+                tracing::error!("database error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::RedisError(e) => {
+                tracing::error!("redis error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::HashError(e) => {
+                tracing::error!("password hashing error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::EmailError(e) => {
+                tracing::error!("email delivery error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::ConfigError(e) => {
+                tracing::error!("configuration error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::TransportError(e) => {
+                tracing::error!("transport error: {e}");
+                "internal server error".to_string()
+            }
+            AppError::AddrParseError(e) => {
+                tracing::error!("invalid server address: {e}");
+                "internal server error".to_string()
+            }
+            AppError::Internal(msg) => {
+                tracing::error!("internal error: {msg}");
+                "internal server error".to_string()
+            }
+            AppError::JwtError(e) => {
+                tracing::warn!("jwt error: {e}");
+                "invalid or expired token".to_string()
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl From<AppError> for Status {
+    fn from(err: AppError) -> Self {
+        let code = err.code();
+        let error_code = err.error_code();
+        let message = err.client_message();
+
+        let mut status = Status::new(code, message);
+        if let Ok(value) = error_code.parse() {
+            status.metadata_mut().insert("x-error-code", value);
+        }
+        status
+    }
+}
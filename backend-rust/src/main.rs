@@ -19,20 +19,22 @@ pub mod auth {
 
 use auth::auth_service_server::{AuthService, AuthServiceServer};
 use auth::{
-    ForgotPasswordRequest, ForgotPasswordResponse, LoginRequest, LoginResponse,
-    ResetPasswordRequest, ResetPasswordResponse, SignUpRequest, SignUpResponse,
-    ValidateTokenRequest, ValidateTokenResponse,
+    ForgotPasswordRequest, ForgotPasswordResponse, LoginRequest, LoginResponse, LogoutRequest,
+    LogoutResponse, RefreshTokenRequest, RefreshTokenResponse, ResetPasswordRequest,
+    ResetPasswordResponse, SignUpRequest, SignUpResponse, ValidateTokenRequest,
+    ValidateTokenResponse, VerifyEmailRequest, VerifyEmailResponse,
 };
 use chrono::{Duration, Utc};
 use config::Settings;
 use error::AppError;
 use models::requests::{
-    ForgotPasswordRequestDto, LoginRequestDto, ResetPasswordRequestDto, SignUpRequestDto,
-    ValidateTokenRequestDto,
+    ForgotPasswordRequestDto, LoginRequestDto, LogoutRequestDto, RefreshTokenRequestDto,
+    ResetPasswordRequestDto, SignUpRequestDto, ValidateTokenRequestDto, VerifyEmailRequestDto,
 };
 use repositories::session::SessionRepository;
 use repositories::user::UserRepository;
 use utils::jwt::TokenManager;
+use utils::mailer::Mailer;
 use utils::password;
 use uuid::Uuid;
 
@@ -42,6 +44,7 @@ pub struct MyAuthService {
     pub redis_client: Client,
     pub user_repo: UserRepository,
     pub settings: Settings,
+    pub mailer: Arc<Mailer>,
 }
 
 #[tonic::async_trait]
@@ -68,10 +71,35 @@ impl AuthService for MyAuthService {
             .await
             .map_err(AppError::DbError)?;
 
+        let verification_token = Uuid::new_v4().to_string();
+
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        session_repo
+            .store_verification_token(&verification_token, user.id, Duration::hours(24))
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let verification_url = format!(
+            "{}?token={}",
+            self.settings.email_verification_url_base, verification_token
+        );
+
+        // The user row is already committed at this point, so a mailer failure must not fail
+        // the whole request: the account would exist but be unrecoverable (unique email
+        // constraint blocks retrying sign_up, and there is no pending verification email).
+        if let Err(e) =
+            self.mailer
+                .send_verification_email(&user.email, &user.first_name, &verification_url)
+        {
+            error!("failed to send verification email to {}: {e}", user.email);
+        }
+
         let reply = SignUpResponse {
             success: true,
 
-            message: "User signed up successfully".into(),
+            message: "User signed up successfully. Please check your email to verify your account."
+                .into(),
 
             user: Some(auth::User {
                 id: user.id.to_string(),
@@ -97,23 +125,57 @@ impl AuthService for MyAuthService {
 
         let dto: LoginRequestDto = req.try_into().map_err(AppError::ValidationError)?;
 
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        let fail_count = session_repo
+            .get_failed_login_count(&dto.email)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        if fail_count >= self.settings.login_fail_threshold as i64 {
+            return Err(AppError::TooManyAttempts.into());
+        }
+
         let user = self
             .user_repo
             .find_by_email(&dto.email)
             .await
             .map_err(AppError::DbError)?;
 
+        let login_fail_window = Duration::minutes(self.settings.login_fail_window_minutes);
+
         let user = match user {
             Some(u) => u,
 
-            None => return Err(AppError::Unauthorized("Invalid credentials".to_string()).into()),
+            None => {
+                session_repo
+                    .record_failed_login(&dto.email, login_fail_window)
+                    .await
+                    .map_err(AppError::RedisError)?;
+
+                return Err(AppError::InvalidCredentials.into());
+            }
         };
 
         let password_matches = password::verify_password(&user.password_hash, &dto.password)
             .map_err(AppError::from)?;
 
         if !password_matches {
-            return Err(AppError::Unauthorized("Invalid credentials".to_string()).into());
+            session_repo
+                .record_failed_login(&dto.email, login_fail_window)
+                .await
+                .map_err(AppError::RedisError)?;
+
+            return Err(AppError::InvalidCredentials.into());
+        }
+
+        session_repo
+            .clear_failed_login(&dto.email)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        if !user.is_active {
+            return Err(AppError::EmailNotVerified.into());
         }
 
         let token_manager = TokenManager::new(self.settings.jwt_secret.clone());
@@ -126,8 +188,6 @@ impl AuthService for MyAuthService {
             .generate_refresh_token(user.id)
             .map_err(AppError::JwtError)?;
 
-        let session_repo = SessionRepository::new(self.redis_client.clone());
-
         session_repo
             .store_refresh_token(
                 user.id,
@@ -191,12 +251,20 @@ impl AuthService for MyAuthService {
                 .await
                 .map_err(AppError::RedisError)?;
 
-            // In a real application, you would send this token via email.
-
-            info!(
-                "Password reset token for user {}: {}",
-                user.email, reset_token
+            let reset_url = format!(
+                "{}?token={}",
+                self.settings.password_reset_url_base, reset_token
             );
+
+            // Don't let a mailer failure escape as an error here: the response must stay
+            // identical regardless of whether the email exists or the send succeeded, or an
+            // attacker can enumerate accounts by watching for transport blips.
+            if let Err(e) = self
+                .mailer
+                .send_password_reset_email(&user.email, &user.first_name, &reset_url)
+            {
+                error!("failed to send password reset email to {}: {e}", user.email);
+            }
         }
 
         let reply = ForgotPasswordResponse {
@@ -231,7 +299,7 @@ impl AuthService for MyAuthService {
 
             None => {
                 return Err(
-                    AppError::BadRequest("Invalid or expired reset token.".to_string()).into(),
+                    AppError::InvalidResetToken.into(),
                 );
             }
         };
@@ -268,6 +336,17 @@ impl AuthService for MyAuthService {
             .validate_token(&dto.access_token)
             .map_err(AppError::JwtError)?;
 
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        let revoked = session_repo
+            .is_jti_revoked(&claims.jti)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        if revoked {
+            return Err(AppError::TokenRevoked.into());
+        }
+
         let user_id = Uuid::parse_str(&claims.sub)
             .map_err(|e| AppError::Internal(format!("Invalid user ID in token: {}", e)))?;
 
@@ -282,7 +361,7 @@ impl AuthService for MyAuthService {
 
             _ => {
                 return Err(
-                    AppError::Unauthorized("User not active or not found".to_string()).into(),
+                    AppError::UserBlocked.into(),
                 );
             }
         };
@@ -305,6 +384,148 @@ impl AuthService for MyAuthService {
 
         Ok(Response::new(reply))
     }
+
+    async fn refresh_token(
+        &self,
+        request: Request<RefreshTokenRequest>,
+    ) -> Result<Response<RefreshTokenResponse>, Status> {
+        info!("Got a RefreshToken request: {:?}", request);
+
+        let req = request.into_inner();
+
+        let dto: RefreshTokenRequestDto = req.try_into().map_err(AppError::ValidationError)?;
+
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        let user_id = session_repo
+            .get_user_id_from_refresh_token(&dto.refresh_token)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let user_id = match user_id {
+            Some(id) => id,
+
+            // Either a forged token or one that was already rotated away; treat both as replay.
+            None => return Err(AppError::InvalidRefreshToken.into()),
+        };
+
+        // Single-use rotation: the presented token is consumed immediately so a second
+        // presentation of the same token (theft/replay) is rejected above.
+        session_repo
+            .revoke_refresh_token(&dto.refresh_token)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let token_manager = TokenManager::new(self.settings.jwt_secret.clone());
+
+        let (access_token, access_exp) = token_manager
+            .generate_access_token(user_id)
+            .map_err(AppError::JwtError)?;
+
+        let (refresh_token, refresh_exp) = token_manager
+            .generate_refresh_token(user_id)
+            .map_err(AppError::JwtError)?;
+
+        session_repo
+            .store_refresh_token(
+                user_id,
+                &refresh_token,
+                Duration::seconds((refresh_exp - Utc::now().timestamp() as usize) as i64),
+            )
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let reply = RefreshTokenResponse {
+            access_token,
+
+            refresh_token,
+
+            expires_in: access_exp as i64,
+        };
+
+        Ok(Response::new(reply))
+    }
+
+    async fn logout(
+        &self,
+        request: Request<LogoutRequest>,
+    ) -> Result<Response<LogoutResponse>, Status> {
+        info!("Got a Logout request: {:?}", request);
+
+        let req = request.into_inner();
+
+        let dto: LogoutRequestDto = req.try_into().map_err(AppError::ValidationError)?;
+
+        let token_manager = TokenManager::new(self.settings.jwt_secret.clone());
+
+        let claims = token_manager
+            .validate_token(&dto.access_token)
+            .map_err(AppError::JwtError)?;
+
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        let remaining_ttl =
+            Duration::seconds(claims.exp as i64 - Utc::now().timestamp()).max(Duration::seconds(1));
+
+        session_repo
+            .revoke_jti(&claims.jti, remaining_ttl)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        session_repo
+            .revoke_refresh_token(&dto.refresh_token)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let reply = LogoutResponse {
+            success: true,
+
+            message: "Logged out successfully".into(),
+        };
+
+        Ok(Response::new(reply))
+    }
+
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<VerifyEmailResponse>, Status> {
+        info!("Got a VerifyEmail request: {:?}", request);
+
+        let req = request.into_inner();
+
+        let dto: VerifyEmailRequestDto = req.try_into().map_err(AppError::ValidationError)?;
+
+        let session_repo = SessionRepository::new(self.redis_client.clone());
+
+        let user_id = session_repo
+            .get_user_id_from_verification_token(&dto.token)
+            .await
+            .map_err(AppError::RedisError)?;
+
+        let user_id = match user_id {
+            Some(id) => id,
+
+            None => {
+                return Err(
+                    AppError::InvalidVerificationToken.into(),
+                );
+            }
+        };
+
+        self.user_repo
+            .activate(user_id)
+            .await
+            .map_err(AppError::DbError)?;
+
+        let reply = VerifyEmailResponse {
+            success: true,
+
+            message: "Email verified successfully".into(),
+        };
+
+        Ok(Response::new(reply))
+    }
 }
 
 #[tokio::main]
@@ -339,6 +560,16 @@ async fn main() -> Result<(), AppError> {
 
     let user_repo = UserRepository::new(pool_arc.clone());
 
+    // 5. Initialize mailer
+
+    let mailer = Arc::new(Mailer::new(
+        &settings.smtp_host,
+        settings.smtp_port,
+        &settings.smtp_username,
+        &settings.smtp_password,
+        &settings.smtp_from_address,
+    )?);
+
     let addr = format!("{}:{}", settings.server_host, settings.server_port)
         .parse()
         .map_err(AppError::from)?;
@@ -351,6 +582,8 @@ async fn main() -> Result<(), AppError> {
         user_repo,
 
         settings,
+
+        mailer,
     };
 
     info!("AuthService server listening on {}", addr);
@@ -0,0 +1,161 @@
+use validator::Validate;
+
+use crate::auth;
+
+#[derive(Debug, Validate)]
+pub struct SignUpRequestDto {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 8, message = "password must be at least 8 characters"))]
+    pub password: String,
+    #[validate(length(min = 1))]
+    pub first_name: String,
+    #[validate(length(min = 1))]
+    pub last_name: String,
+}
+
+impl TryFrom<auth::SignUpRequest> for SignUpRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::SignUpRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            email: req.email,
+            password: req.password,
+            first_name: req.first_name,
+            last_name: req.last_name,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct LoginRequestDto {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 1))]
+    pub password: String,
+}
+
+impl TryFrom<auth::LoginRequest> for LoginRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::LoginRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            email: req.email,
+            password: req.password,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct ForgotPasswordRequestDto {
+    #[validate(email)]
+    pub email: String,
+}
+
+impl TryFrom<auth::ForgotPasswordRequest> for ForgotPasswordRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::ForgotPasswordRequest) -> Result<Self, Self::Error> {
+        let dto = Self { email: req.email };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct ResetPasswordRequestDto {
+    #[validate(length(min = 1))]
+    pub token: String,
+    #[validate(length(min = 8, message = "password must be at least 8 characters"))]
+    pub new_password: String,
+}
+
+impl TryFrom<auth::ResetPasswordRequest> for ResetPasswordRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::ResetPasswordRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            token: req.token,
+            new_password: req.new_password,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct RefreshTokenRequestDto {
+    #[validate(length(min = 1))]
+    pub refresh_token: String,
+}
+
+impl TryFrom<auth::RefreshTokenRequest> for RefreshTokenRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::RefreshTokenRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            refresh_token: req.refresh_token,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct LogoutRequestDto {
+    #[validate(length(min = 1))]
+    pub access_token: String,
+    #[validate(length(min = 1))]
+    pub refresh_token: String,
+}
+
+impl TryFrom<auth::LogoutRequest> for LogoutRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::LogoutRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            access_token: req.access_token,
+            refresh_token: req.refresh_token,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct VerifyEmailRequestDto {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+impl TryFrom<auth::VerifyEmailRequest> for VerifyEmailRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::VerifyEmailRequest) -> Result<Self, Self::Error> {
+        let dto = Self { token: req.token };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
+
+#[derive(Debug, Validate)]
+pub struct ValidateTokenRequestDto {
+    #[validate(length(min = 1))]
+    pub access_token: String,
+}
+
+impl TryFrom<auth::ValidateTokenRequest> for ValidateTokenRequestDto {
+    type Error = String;
+
+    fn try_from(req: auth::ValidateTokenRequest) -> Result<Self, Self::Error> {
+        let dto = Self {
+            access_token: req.access_token,
+        };
+        dto.validate().map_err(|e| e.to_string())?;
+        Ok(dto)
+    }
+}
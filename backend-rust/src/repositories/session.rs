@@ -0,0 +1,210 @@
+use chrono::Duration;
+use redis::{AsyncCommands, Client};
+use uuid::Uuid;
+
+const REFRESH_TOKEN_PREFIX: &str = "refresh_token";
+const RESET_TOKEN_PREFIX: &str = "reset_token";
+const VERIFICATION_TOKEN_PREFIX: &str = "verification_token";
+const LOGIN_FAIL_PREFIX: &str = "login_fail";
+const JTI_DENYLIST_PREFIX: &str = "jti_denylist";
+
+#[derive(Debug, Clone)]
+pub struct SessionRepository {
+    client: Client,
+}
+
+impl SessionRepository {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn store_refresh_token(
+        &self,
+        user_id: Uuid,
+        token: &str,
+        ttl: Duration,
+    ) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{REFRESH_TOKEN_PREFIX}:{token}");
+        conn.set_ex(key, user_id.to_string(), ttl.num_seconds() as u64)
+            .await
+    }
+
+    pub async fn get_user_id_from_refresh_token(
+        &self,
+        token: &str,
+    ) -> redis::RedisResult<Option<Uuid>> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{REFRESH_TOKEN_PREFIX}:{token}");
+        let value: Option<String> = conn.get(key).await?;
+        Ok(value.and_then(|v| Uuid::parse_str(&v).ok()))
+    }
+
+    pub async fn revoke_refresh_token(&self, token: &str) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{REFRESH_TOKEN_PREFIX}:{token}");
+        conn.del(key).await
+    }
+
+    /// Increments the failed-login counter for `email`, starting a fixed expiry window the
+    /// first time it is created (later failures do not extend it), and returns the new count.
+    pub async fn record_failed_login(
+        &self,
+        email: &str,
+        window: Duration,
+    ) -> redis::RedisResult<i64> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{LOGIN_FAIL_PREFIX}:{email}");
+        let count: i64 = conn.incr(&key, 1).await?;
+        if count == 1 {
+            conn.expire(&key, window.num_seconds()).await?;
+        }
+        Ok(count)
+    }
+
+    pub async fn get_failed_login_count(&self, email: &str) -> redis::RedisResult<i64> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{LOGIN_FAIL_PREFIX}:{email}");
+        let count: Option<i64> = conn.get(key).await?;
+        Ok(count.unwrap_or(0))
+    }
+
+    pub async fn clear_failed_login(&self, email: &str) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{LOGIN_FAIL_PREFIX}:{email}");
+        conn.del(key).await
+    }
+
+    /// Records `jti` on the access-token denylist for the remainder of the token's lifetime,
+    /// so `validate_token` can reject it even though the signature is still valid.
+    pub async fn revoke_jti(&self, jti: &str, ttl: Duration) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{JTI_DENYLIST_PREFIX}:{jti}");
+        let ttl_seconds = ttl.num_seconds().max(1) as u64;
+        conn.set_ex(key, true, ttl_seconds).await
+    }
+
+    pub async fn is_jti_revoked(&self, jti: &str) -> redis::RedisResult<bool> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{JTI_DENYLIST_PREFIX}:{jti}");
+        conn.exists(key).await
+    }
+
+    pub async fn store_reset_token(
+        &self,
+        token: &str,
+        user_id: Uuid,
+        ttl: Duration,
+    ) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{RESET_TOKEN_PREFIX}:{token}");
+        conn.set_ex(key, user_id.to_string(), ttl.num_seconds() as u64)
+            .await
+    }
+
+    pub async fn get_user_id_from_reset_token(
+        &self,
+        token: &str,
+    ) -> redis::RedisResult<Option<Uuid>> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{RESET_TOKEN_PREFIX}:{token}");
+        let value: Option<String> = conn.get(key).await?;
+        Ok(value.and_then(|v| Uuid::parse_str(&v).ok()))
+    }
+
+    pub async fn store_verification_token(
+        &self,
+        token: &str,
+        user_id: Uuid,
+        ttl: Duration,
+    ) -> redis::RedisResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{VERIFICATION_TOKEN_PREFIX}:{token}");
+        conn.set_ex(key, user_id.to_string(), ttl.num_seconds() as u64)
+            .await
+    }
+
+    pub async fn get_user_id_from_verification_token(
+        &self,
+        token: &str,
+    ) -> redis::RedisResult<Option<Uuid>> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let key = format!("{VERIFICATION_TOKEN_PREFIX}:{token}");
+        let value: Option<String> = conn.get(key).await?;
+        Ok(value.and_then(|v| Uuid::parse_str(&v).ok()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Requires a local Redis instance; override with TEST_REDIS_URL if it runs elsewhere.
+    fn test_client() -> Client {
+        let url =
+            std::env::var("TEST_REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".into());
+        Client::open(url).expect("valid redis url")
+    }
+
+    #[tokio::test]
+    async fn failed_login_counter_increments_and_clears() {
+        let repo = SessionRepository::new(test_client());
+        let email = format!("throttle-test-{}@example.com", Uuid::new_v4());
+
+        assert_eq!(repo.get_failed_login_count(&email).await.unwrap(), 0);
+
+        let first = repo
+            .record_failed_login(&email, Duration::minutes(15))
+            .await
+            .unwrap();
+        assert_eq!(first, 1);
+
+        let second = repo
+            .record_failed_login(&email, Duration::minutes(15))
+            .await
+            .unwrap();
+        assert_eq!(second, 2);
+
+        assert_eq!(repo.get_failed_login_count(&email).await.unwrap(), 2);
+
+        repo.clear_failed_login(&email).await.unwrap();
+        assert_eq!(repo.get_failed_login_count(&email).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn jti_denylist_round_trip() {
+        let repo = SessionRepository::new(test_client());
+        let jti = Uuid::new_v4().to_string();
+
+        assert!(!repo.is_jti_revoked(&jti).await.unwrap());
+
+        repo.revoke_jti(&jti, Duration::seconds(30)).await.unwrap();
+
+        assert!(repo.is_jti_revoked(&jti).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn refresh_token_is_single_use() {
+        let repo = SessionRepository::new(test_client());
+        let user_id = Uuid::new_v4();
+        let token = Uuid::new_v4().to_string();
+
+        repo.store_refresh_token(user_id, &token, Duration::minutes(30))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repo.get_user_id_from_refresh_token(&token).await.unwrap(),
+            Some(user_id)
+        );
+
+        repo.revoke_refresh_token(&token).await.unwrap();
+
+        // Once revoked (rotated away), a second presentation of the same token must look up
+        // as absent so `refresh_token` can treat it as replay.
+        assert_eq!(
+            repo.get_user_id_from_refresh_token(&token).await.unwrap(),
+            None
+        );
+    }
+}
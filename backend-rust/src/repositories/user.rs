@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::user::User;
+
+#[derive(Debug, Clone)]
+pub struct UserRepository {
+    pool: Arc<PgPool>,
+}
+
+impl UserRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        email: &str,
+        password_hash: &str,
+        first_name: &str,
+        last_name: &str,
+    ) -> Result<User, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "INSERT INTO users (email, password_hash, first_name, last_name) \
+             VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(email)
+        .bind(password_hash)
+        .bind(first_name)
+        .bind(last_name)
+        .fetch_one(self.pool.as_ref())
+        .await
+    }
+
+    pub async fn find_by_email(&self, email: &str) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
+            .bind(email)
+            .fetch_optional(self.pool.as_ref())
+            .await
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+            .bind(id)
+            .fetch_optional(self.pool.as_ref())
+            .await
+    }
+
+    pub async fn update_last_login(&self, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE users SET last_login_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(self.pool.as_ref())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_password(&self, id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE users SET password_hash = $1 WHERE id = $2")
+            .bind(password_hash)
+            .bind(id)
+            .execute(self.pool.as_ref())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn activate(&self, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE users SET is_active = true WHERE id = $1")
+            .bind(id)
+            .execute(self.pool.as_ref())
+            .await?;
+        Ok(())
+    }
+}
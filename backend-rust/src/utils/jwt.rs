@@ -0,0 +1,73 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub iat: usize,
+    pub exp: usize,
+    /// Unique id for this token, used to key the revocation denylist in `SessionRepository`.
+    pub jti: String,
+}
+
+pub struct TokenManager {
+    secret: String,
+}
+
+impl TokenManager {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    pub fn generate_access_token(
+        &self,
+        user_id: Uuid,
+    ) -> Result<(String, usize), jsonwebtoken::errors::Error> {
+        self.generate_token(user_id, Duration::minutes(ACCESS_TOKEN_TTL_MINUTES))
+    }
+
+    pub fn generate_refresh_token(
+        &self,
+        user_id: Uuid,
+    ) -> Result<(String, usize), jsonwebtoken::errors::Error> {
+        self.generate_token(user_id, Duration::days(REFRESH_TOKEN_TTL_DAYS))
+    }
+
+    fn generate_token(
+        &self,
+        user_id: Uuid,
+        ttl: Duration,
+    ) -> Result<(String, usize), jsonwebtoken::errors::Error> {
+        let now = Utc::now();
+        let exp = (now + ttl).timestamp() as usize;
+        let claims = Claims {
+            sub: user_id.to_string(),
+            iat: now.timestamp() as usize,
+            exp,
+            jti: Uuid::new_v4().to_string(),
+        };
+
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_bytes()),
+        )?;
+
+        Ok((token, exp))
+    }
+
+    pub fn validate_token(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &Validation::default(),
+        )?;
+
+        Ok(data.claims)
+    }
+}
@@ -0,0 +1,131 @@
+use handlebars::Handlebars;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use serde::Serialize;
+
+use crate::error::AppError;
+
+const RESET_PASSWORD_TEMPLATE: &str = include_str!("../../templates/reset_password.hbs");
+const VERIFY_EMAIL_TEMPLATE: &str = include_str!("../../templates/verify_email.hbs");
+
+#[derive(Serialize)]
+struct ResetPasswordContext<'a> {
+    first_name: &'a str,
+    reset_url: &'a str,
+}
+
+#[derive(Serialize)]
+struct VerifyEmailContext<'a> {
+    first_name: &'a str,
+    verification_url: &'a str,
+}
+
+/// Wraps an SMTP transport and the set of HTML email templates the service sends.
+pub struct Mailer {
+    transport: SmtpTransport,
+    from_address: String,
+    templates: Handlebars<'static>,
+}
+
+impl Mailer {
+    pub fn new(
+        smtp_host: &str,
+        smtp_port: u16,
+        smtp_username: &str,
+        smtp_password: &str,
+        from_address: &str,
+    ) -> Result<Self, AppError> {
+        let credentials = Credentials::new(smtp_username.to_string(), smtp_password.to_string());
+
+        let transport = SmtpTransport::relay(smtp_host)
+            .map_err(|e| AppError::EmailError(e.to_string()))?
+            .port(smtp_port)
+            .credentials(credentials)
+            .build();
+
+        let mut templates = Handlebars::new();
+        templates
+            .register_template_string("reset_password", RESET_PASSWORD_TEMPLATE)
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+        templates
+            .register_template_string("verify_email", VERIFY_EMAIL_TEMPLATE)
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+
+        Ok(Self {
+            transport,
+            from_address: from_address.to_string(),
+            templates,
+        })
+    }
+
+    pub fn send_password_reset_email(
+        &self,
+        to_address: &str,
+        first_name: &str,
+        reset_url: &str,
+    ) -> Result<(), AppError> {
+        let body = self
+            .templates
+            .render(
+                "reset_password",
+                &ResetPasswordContext {
+                    first_name,
+                    reset_url,
+                },
+            )
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+
+        self.send_html_email(to_address, "Reset your password", body)
+    }
+
+    pub fn send_verification_email(
+        &self,
+        to_address: &str,
+        first_name: &str,
+        verification_url: &str,
+    ) -> Result<(), AppError> {
+        let body = self
+            .templates
+            .render(
+                "verify_email",
+                &VerifyEmailContext {
+                    first_name,
+                    verification_url,
+                },
+            )
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+
+        self.send_html_email(to_address, "Verify your email address", body)
+    }
+
+    fn send_html_email(&self, to_address: &str, subject: &str, body: String) -> Result<(), AppError> {
+        let email = Message::builder()
+            .from(
+                self.from_address
+                    .parse()
+                    .map_err(|e: lettre::address::AddressError| AppError::EmailError(e.to_string()))?,
+            )
+            .to(to_address
+                .parse()
+                .map_err(|e: lettre::address::AddressError| AppError::EmailError(e.to_string()))?)
+            .subject(subject)
+            .header(ContentType::TEXT_HTML)
+            .body(body)
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+
+        self.transport
+            .send(&email)
+            .map_err(|e| AppError::EmailError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Mailer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mailer")
+            .field("from_address", &self.from_address)
+            .finish()
+    }
+}